@@ -1,72 +1,154 @@
 use std::{env, fs, io, process};
 use std::cmp::Ordering;
+use std::collections::BTreeMap;
 use std::io::{stdin, stdout, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use colored::Colorize;
 use serde::{Deserialize, Serialize};
 
 const NAME: &str = "godot-cli";
 const CONFIG: &str = "config";
+const MAX_ALIAS_DEPTH: u8 = 8;
+const LOCAL_CONFIG_FILE: &str = ".godot-cli.toml";
 
-#[derive(Default, Debug, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 struct Config {
-    godot_exec: String,
-    project_dir: String
+    #[serde(default)]
+    project_dir: String,
+    #[serde(default)]
+    template_dir: String,
+    #[serde(default)]
+    aliases: BTreeMap<String, Vec<String>>,
+    #[serde(default)]
+    profiles: BTreeMap<String, String>,
+    #[serde(default)]
+    default_profile: String,
+    #[serde(default)]
+    tags: BTreeMap<String, Vec<String>>
+}
+
+#[derive(Serialize)]
+struct DoctorReport {
+    global_config: String,
+    local_config: String,
+    profile: String,
+    executable: String,
+    executable_exists: bool,
+    executable_executable: bool,
+    godot_version: String,
+    os: &'static str,
+    arch: &'static str,
+    project_count: usize,
+    unset_config_entries: Vec<&'static str>
 }
 
 
 fn main() {
-    let mut config: Config;
+    let mut global_config: Config;
     match confy::load::<Config>(NAME, CONFIG) {
-        Ok(c) => config = c,
+        Ok(c) => global_config = c,
         Err(e) => {
             print_config_error(e);
             if prompt("reset to default?", None) {
-                config = Config::default();
+                global_config = Config::default();
             } else {
                 return;
             }
         }
     }
 
+    let local_path = find_local_config_path();
+    let local_config = local_path.as_ref().map(|p| load_config_file(p));
+    // `config` is the merged view used for reads throughout; mutations always
+    // target `global_config` (the pristine value loaded above) so a local
+    // override is never baked into the user's global file on write
+    let mut config = global_config.clone();
+    if let Some(local) = &local_config {
+        config = merge_local_over_global(config, local);
+    }
+
     let mut args = env::args().skip(1).collect::<Vec<String>>();
     if args.is_empty() { args.push(String::from("help")); }
-    let arg_len = args.len();
 
+    let mut profile_flag: Option<String> = None;
+    let mut take_profile_value = false;
     args.retain(|arg| {
+        if take_profile_value {
+            profile_flag = Some(arg.clone());
+            take_profile_value = false;
+            return false;
+        }
+
         match arg.as_str() {
             "--no-color" => { colored::control::set_override(false); }
             "--force-color" => { colored::control::set_override(true); }
-            _ => {
-                return if arg.starts_with("--") {
-                    warn_msg(&format!("unknown arg {}", arg.bold()));
-                    false
-                } else {
-                    true
-                }
-            }
+            "--profile" => { take_profile_value = true; }
+            // other "--" flags (--template, --local, ...) are action-specific and left in
+            // place for the action's own branch to parse and validate
+            _ => return true
         }
         false
     });
 
+    // recomputed after the retain loop strips global flags, so --profile/--no-color
+    // don't inflate the per-action arg-count checks below
+    let mut arg_len = args.len();
+
+    let mut expansions = 0u8;
+    while let Some(expansion) = config.aliases.get(&args[0]).cloned() {
+        expansions += 1;
+        if expansions > MAX_ALIAS_DEPTH {
+            err_msg(&format!("alias expansion exceeded depth {MAX_ALIAS_DEPTH} (possible cycle)"));
+            return;
+        }
+        if expansion.is_empty() {
+            err_msg(&format!("alias \"{}\" expands to nothing", args[0]));
+            return;
+        }
+        args.splice(0..1, expansion);
+        arg_len = args.len();
+    }
+
     let action = args[0].as_str();
     match action {
         "help" | "/?" => print_action_help(),
         "new" | "create" => {
-            if !args_count(2, arg_len, Ordering::Equal) { return; }
+            if arg_len != 2 && arg_len != 4 {
+                err_msg(&format!("expected 2 or 4 args, got {arg_len}"));
+                return;
+            }
 
-            if config.godot_exec.is_empty() || config.project_dir.is_empty() {
-                print_missing_config_notice(vec!("godot_exec", "project_dir"));
+            if config.project_dir.is_empty() {
+                print_missing_config_notice(vec!("project_dir"));
+                return;
+            }
+            if config.profiles.is_empty() {
+                print_missing_profile_notice();
                 return;
             }
 
             let name = &args[1];
             if !is_valid_name(name) { return; }
 
+            let template_id = if args.len() == 4 {
+                if args[2] != "--template" {
+                    err_msg(&format!("unknown arg {}", args[2].bold()));
+                    return;
+                }
+                args[3].as_str()
+            } else {
+                "empty"
+            };
+
+            let exec = match resolve_profile_exec(&config, &profile_flag) {
+                Ok(exec) => exec,
+                Err(e) => { err_msg(&e); return; }
+            };
+
             let project_dir = format!("{}/{name}", config.project_dir);
 
-            if !prompt(&format!("confirm {} of project \"{}\"?", "creation".cyan().bold(), project_dir.bold()), None) { return; }
+            if !prompt(&format!("confirm {} of project \"{}\" from template \"{}\"?", "creation".cyan().bold(), project_dir.bold(), template_id.bold()), None) { return; }
 
             match fs::create_dir(&project_dir) {
                 Ok(_) => {}
@@ -78,48 +160,125 @@ fn main() {
                 }
             }
 
-            {
-                let mut file = fs::File::create(format!("{project_dir}/project.godot")).unwrap();
-                file.write_all(format!("[application]\n\nconfig/name=\"{name}\"").as_bytes())
-                    .unwrap();
+            let templates_dir = resolve_templates_dir(&config);
+            if let Err(e) = scaffold_project(&project_dir, name, template_id, &templates_dir) {
+                err_msg(&format!("failed to scaffold project: {e}"));
+                return;
             }
 
-            open_godot(vec!("-e", "--path", &project_dir));
+            open_godot(exec, vec!("-e", "--path", &project_dir));
         }
         "open" => {
             if !args_count(2, arg_len, Ordering::Equal) { return; }
 
-            if config.godot_exec.is_empty() || config.project_dir.is_empty() {
-                print_missing_config_notice(vec!("godot_exec", "project_dir"));
+            if config.project_dir.is_empty() {
+                print_missing_config_notice(vec!("project_dir"));
+                return;
+            }
+            if config.profiles.is_empty() {
+                print_missing_profile_notice();
                 return;
             }
 
             let name = &args[1];
-            if !is_valid_name(name) { return; }
-
-            let project_dir = format!("{}/{name}", config.project_dir);
-            let path = Path::new(&project_dir);
-            if !path.exists() || path.is_file() {
-                err_msg("invalid path or no permission");
+            let targets = match resolve_targets(&config, name) {
+                Some(t) => t,
+                None => {
+                    err_msg(&format!("unknown tag {}", name.trim_start_matches('@').bold()));
+                    return;
+                }
+            };
+            if targets.is_empty() {
+                err_msg(&format!("tag {} has no projects", name.trim_start_matches('@').bold()));
                 return;
             }
+            for target in &targets {
+                if !is_valid_name(target) { return; }
+            }
 
-            println!("opening project {}...", project_dir.bold());
+            let exec = match resolve_profile_exec(&config, &profile_flag) {
+                Ok(exec) => exec,
+                Err(e) => { err_msg(&e); return; }
+            };
 
-            open_godot(vec!("-e", "--path", &project_dir));
+            if targets.len() > 4 && !prompt(&format!("open {} tagged projects?", targets.len().to_string().bold()), None) { return; }
+
+            let mut project_dirs = Vec::with_capacity(targets.len());
+            for target in &targets {
+                let project_dir = format!("{}/{target}", config.project_dir);
+                let path = Path::new(&project_dir);
+                if !path.exists() || path.is_file() {
+                    err_msg(&format!("invalid path or no permission: {project_dir}"));
+                    return;
+                }
+                project_dirs.push(project_dir);
+            }
+
+            for project_dir in &project_dirs {
+                println!("opening project {}...", project_dir.bold());
+                open_godot(exec, vec!("-e", "--path", project_dir));
+            }
         }
         "run" => {
             if !args_count(1, arg_len, Ordering::Greater) { return; }
             if !args_count(4, arg_len, Ordering::Less) { return; }
 
-            if config.godot_exec.is_empty() || config.project_dir.is_empty() {
-                print_missing_config_notice(vec!("godot_exec", "project_dir"));
+            if config.project_dir.is_empty() {
+                print_missing_config_notice(vec!("project_dir"));
                 return;
             }
+            if config.profiles.is_empty() {
+                print_missing_profile_notice();
+                return;
+            }
+
+            let exec = match resolve_profile_exec(&config, &profile_flag) {
+                Ok(exec) => exec,
+                Err(e) => { err_msg(&e); return; }
+            };
 
             let name = &args[1];
-            if !is_valid_name(name) { return; }
+            let targets = match resolve_targets(&config, name) {
+                Some(t) => t,
+                None => {
+                    err_msg(&format!("unknown tag {}", name.trim_start_matches('@').bold()));
+                    return;
+                }
+            };
+            if targets.is_empty() {
+                err_msg(&format!("tag {} has no projects", name.trim_start_matches('@').bold()));
+                return;
+            }
+            for target in &targets {
+                if !is_valid_name(target) { return; }
+            }
+
+            if targets.len() > 1 {
+                if arg_len > 2 {
+                    warn_msg("instance count is ignored when running a tag batch");
+                }
+
+                if targets.len() > 4 && !prompt(&format!("run {} tagged projects?", targets.len().to_string().bold()), None) { return; }
+
+                let mut project_dirs = Vec::with_capacity(targets.len());
+                for target in &targets {
+                    let project_dir = format!("{}/{target}", config.project_dir);
+                    let path = Path::new(&project_dir);
+                    if !path.exists() || path.is_file() {
+                        err_msg(&format!("invalid path or no permission: {project_dir}"));
+                        return;
+                    }
+                    project_dirs.push(project_dir);
+                }
 
+                println!("running {} tagged projects...", targets.len().to_string().bold());
+                for project_dir in &project_dirs {
+                    open_godot(exec, vec!("--path", project_dir));
+                }
+                return;
+            }
+
+            let name = &targets[0];
             let instances_string: String;
             let instances: u8;
             if arg_len > 2 {
@@ -142,13 +301,13 @@ fn main() {
                 err_msg("invalid path or no permission");
                 return;
             }
-            
+
             if instances > 4 && !prompt(&format!("run {} instances of the project?", instances_string.bold()), None) { return; }
-            
+
             println!("running project {} with {instances} instances...", project_dir.bold());
 
             for _ in 0..instances {
-                open_godot(vec!("--path", &project_dir));
+                open_godot(exec, vec!("--path", &project_dir));
             }
         }
         "list" => {
@@ -159,31 +318,26 @@ fn main() {
                 return;
             }
 
-            for dir in fs::read_dir(&config.project_dir).unwrap() {
-                match dir {
-                    Ok(entry) => {
-                        let mut path = entry.path();
-
-                        let path_meta = path.metadata().unwrap();
-                        if path_meta.is_file() { continue; }
-
-                        path.push("project.godot");
-                        if !path.is_file() { continue; }
-
-                        println!("{:?}", entry.file_name());
+            for name in enumerate_projects(&config.project_dir) {
+                println!("{name}");
+            }
+        }
+        "completions" => {
+            if !args_count(2, arg_len, Ordering::Equal) { return; }
 
-                    }
-                    Err(e) => {
-                        println!("{e}");
-                    }
-                }
+            let shell = args[1].as_str();
+            match shell {
+                "bash" => print!("{}", completions_bash()),
+                "zsh" => print!("{}", completions_zsh()),
+                "fish" => print!("{}", completions_fish()),
+                _ => err_msg(&format!("unsupported shell {}, expected bash, zsh or fish", shell.bold()))
             }
         }
         "delete" | "remove" => {
             if !args_count(2, arg_len, Ordering::Equal) { return; }
 
-            if config.godot_exec.is_empty() || config.project_dir.is_empty() {
-                print_missing_config_notice(vec!("godot_exec", "project_dir"));
+            if config.project_dir.is_empty() {
+                print_missing_config_notice(vec!("project_dir"));
                 return;
             }
 
@@ -200,6 +354,78 @@ fn main() {
 
             fs::remove_dir_all(project_dir).unwrap()
         }
+        "tag" => {
+            if !args_count(1, arg_len, Ordering::Greater) { return; }
+
+            let tag_action = args[1].as_str();
+            match tag_action {
+                "add" => {
+                    if !args_count(4, arg_len, Ordering::Equal) { return; }
+
+                    let tag_name = args[2].to_string();
+                    let project = args[3].to_string();
+                    if !is_valid_name(&project) { return; }
+
+                    let projects = global_config.tags.entry(tag_name).or_default();
+                    if !projects.contains(&project) {
+                        projects.push(project);
+                    }
+                }
+                "remove" => {
+                    if !args_count(4, arg_len, Ordering::Equal) { return; }
+
+                    let tag_name = args[2].as_str();
+                    let project = args[3].as_str();
+                    if let Some(projects) = global_config.tags.get_mut(tag_name) {
+                        projects.retain(|p| p != project);
+                        if projects.is_empty() {
+                            global_config.tags.remove(tag_name);
+                        }
+                    }
+                }
+                "list" => {
+                    if arg_len != 2 && arg_len != 3 {
+                        err_msg(&format!("expected 2 or 3 args, got {arg_len}"));
+                        return;
+                    }
+
+                    if arg_len == 3 {
+                        let tag_name = args[2].as_str();
+                        match config.tags.get(tag_name) {
+                            Some(projects) => projects.iter().for_each(|p| println!("{p}")),
+                            None => err_msg(&format!("unknown tag {}", tag_name.bold()))
+                        }
+                    } else {
+                        for (tag_name, projects) in &config.tags {
+                            println!("{} = {}", tag_name.bold(), projects.join(" "));
+                        }
+                    }
+                    return;
+                }
+                _ => {
+                    err_msg(&format!("invalid action {}", tag_action.bold()));
+                    return;
+                }
+            }
+
+            confy::store(NAME, CONFIG, global_config).unwrap_or_else(|e| {
+                err_msg(&format!("failed to save config: {e}"));
+            });
+        }
+        "doctor" | "diagnose" => {
+            if arg_len != 1 && arg_len != 2 {
+                err_msg(&format!("expected 1 or 2 args, got {arg_len}"));
+                return;
+            }
+
+            let json_flag = arg_len == 2;
+            if json_flag && args[1] != "--json" {
+                err_msg(&format!("unknown arg {}", args[1].bold()));
+                return;
+            }
+
+            print_doctor_report(&config, &local_path, &profile_flag, json_flag);
+        }
         "config" => {
             if arg_len == 1 {
                 println!("{} {}\n", "location:".green().bold(), confy::get_configuration_file_path(NAME, CONFIG).unwrap().to_string_lossy());
@@ -216,32 +442,90 @@ fn main() {
 
                     let entry = args[2].as_str();
                     match entry {
-                        "godot_exec" => println!("{}", config.godot_exec),
                         "project_dir" => println!("{}", config.project_dir),
+                        "template_dir" => println!("{}", config.template_dir),
+                        "default_profile" => println!("{}", config.default_profile),
                         _ => err_msg(&format!("unknown config entry {}", entry.bold()))
                     }
+                    return;
                 }
                 "set" => {
-                    if !args_count(4, arg_len, Ordering::Equal) { return; }
+                    if arg_len != 4 && arg_len != 5 {
+                        err_msg(&format!("expected 4 or 5 args, got {arg_len}"));
+                        return;
+                    }
+
+                    let local_flag = arg_len == 5;
+                    if local_flag && args[4] != "--local" {
+                        err_msg(&format!("unknown arg {}", args[4].bold()));
+                        return;
+                    }
 
                     let entry = args[2].as_str();
                     let value = args[3].as_str();
+
+                    if local_flag {
+                        let path = local_path.clone().unwrap_or_else(|| PathBuf::from(LOCAL_CONFIG_FILE));
+                        let mut local = local_config.clone().unwrap_or_default();
+                        match entry {
+                            "default_profile" => {
+                                if !config.profiles.contains_key(value) {
+                                    err_msg(&format!("unknown profile {}", value.bold()));
+                                    return;
+                                }
+                                local.default_profile = value.to_string();
+                            },
+                            "project_dir" => {
+                                let path = Path::new(value);
+                                if !path.exists() || path.is_file() {
+                                    err_msg("invalid path or no permission");
+                                    return;
+                                }
+                                local.project_dir = value.to_string();
+                            }
+                            "template_dir" => {
+                                let path = Path::new(value);
+                                if !path.exists() || path.is_file() {
+                                    err_msg("invalid path or no permission");
+                                    return;
+                                }
+                                local.template_dir = value.to_string();
+                            }
+                            _ => {
+                                err_msg(&format!("unknown config entry {}", entry.bold()));
+                                return;
+                            }
+                        }
+
+                        if let Err(e) = write_local_config(&path, &local) {
+                            err_msg(&format!("failed to save local config: {e}"));
+                        }
+                        return;
+                    }
+
                     match entry {
-                        "godot_exec" => {
-                            let path = Path::new(value);
-                            if !path.exists() || path.is_dir() {
-                                err_msg("invalid path or no permission");
+                        "default_profile" => {
+                            if !config.profiles.contains_key(value) {
+                                err_msg(&format!("unknown profile {}", value.bold()));
                                 return;
                             }
-                            config.godot_exec = value.to_string();
+                            global_config.default_profile = value.to_string();
                         },
                         "project_dir" => {
                             let path = Path::new(value);
-                            if !path.exists() && path.is_file() {
+                            if !path.exists() || path.is_file() {
+                                err_msg("invalid path or no permission");
+                                return;
+                            }
+                            global_config.project_dir = value.to_string();
+                        }
+                        "template_dir" => {
+                            let path = Path::new(value);
+                            if !path.exists() || path.is_file() {
                                 err_msg("invalid path or no permission");
                                 return;
                             }
-                            config.project_dir = value.to_string();
+                            global_config.template_dir = value.to_string();
                         }
                         _ => err_msg(&format!("unknown config entry {}", entry.bold()))
                     }
@@ -251,14 +535,126 @@ fn main() {
 
                     let entry = args[2].as_str();
                     match entry {
-                        "godot_exec" => config.godot_exec.clear(),
-                        "project_dir" => config.project_dir.clear(),
+                        "project_dir" => global_config.project_dir.clear(),
+                        "template_dir" => global_config.template_dir.clear(),
+                        "default_profile" => global_config.default_profile.clear(),
                         _ => err_msg(&format!("unknown config entry {}", entry.bold()))
                     }
                 }
                 "clear" => {
                     if prompt("confirm deletion of config?", None) {
-                        config = Config::default();
+                        global_config = Config::default();
+                    }
+                }
+                "where" => {
+                    if !args_count(2, arg_len, Ordering::Equal) { return; }
+
+                    let global_path = confy::get_configuration_file_path(NAME, CONFIG).unwrap();
+                    println!("{} {}", "global:".green().bold(), global_path.to_string_lossy());
+                    match &local_path {
+                        Some(p) => println!("{} {}", "local:".green().bold(), p.to_string_lossy()),
+                        None => println!("{} none found", "local:".green().bold())
+                    }
+                    println!();
+
+                    let local_ref = local_config.as_ref();
+                    let source = |is_local_set: bool| -> String {
+                        if is_local_set {
+                            local_path.as_ref().unwrap().to_string_lossy().to_string()
+                        } else {
+                            global_path.to_string_lossy().to_string()
+                        }
+                    };
+
+                    println!("{}: {}", "project_dir".bold(), source(local_ref.is_some_and(|l| !l.project_dir.is_empty())));
+                    println!("{}: {}", "template_dir".bold(), source(local_ref.is_some_and(|l| !l.template_dir.is_empty())));
+                    println!("{}: {}", "default_profile".bold(), source(local_ref.is_some_and(|l| !l.default_profile.is_empty())));
+                    println!("{}: {}", "profiles".bold(), source(local_ref.is_some_and(|l| !l.profiles.is_empty())));
+                    println!("{}: {}", "aliases".bold(), source(local_ref.is_some_and(|l| !l.aliases.is_empty())));
+                    println!("{}: {}", "tags".bold(), source(local_ref.is_some_and(|l| !l.tags.is_empty())));
+                    return;
+                }
+                "alias" => {
+                    if !args_count(2, arg_len, Ordering::Greater) { return; }
+
+                    let alias_action = args[2].as_str();
+                    match alias_action {
+                        "get" => {
+                            if !args_count(4, arg_len, Ordering::Equal) { return; }
+
+                            let alias_name = args[3].as_str();
+                            match config.aliases.get(alias_name) {
+                                Some(expansion) => println!("{}", expansion.join(" ")),
+                                None => err_msg(&format!("unknown alias {}", alias_name.bold()))
+                            }
+                            return;
+                        }
+                        "set" => {
+                            if !args_count(4, arg_len, Ordering::Greater) { return; }
+
+                            let alias_name = args[3].to_string();
+                            let expansion = args[4..].to_vec();
+                            global_config.aliases.insert(alias_name, expansion);
+                        }
+                        "delete" | "remove" => {
+                            if !args_count(4, arg_len, Ordering::Equal) { return; }
+
+                            let alias_name = args[3].as_str();
+                            global_config.aliases.remove(alias_name);
+                        }
+                        "list" => {
+                            if !args_count(3, arg_len, Ordering::Equal) { return; }
+
+                            for (alias_name, expansion) in &config.aliases {
+                                println!("{} = {}", alias_name.bold(), expansion.join(" "));
+                            }
+                            return;
+                        }
+                        _ => {
+                            err_msg(&format!("invalid action {}", alias_action.bold()));
+                            return;
+                        }
+                    }
+                }
+                "profile" => {
+                    if !args_count(2, arg_len, Ordering::Greater) { return; }
+
+                    let profile_action = args[2].as_str();
+                    match profile_action {
+                        "add" => {
+                            if !args_count(5, arg_len, Ordering::Equal) { return; }
+
+                            let profile_name = args[3].to_string();
+                            let value = args[4].as_str();
+                            let path = Path::new(value);
+                            if !path.exists() || path.is_dir() {
+                                err_msg("invalid path or no permission");
+                                return;
+                            }
+                            global_config.profiles.insert(profile_name, value.to_string());
+                        }
+                        "remove" => {
+                            if !args_count(4, arg_len, Ordering::Equal) { return; }
+
+                            let profile_name = args[3].as_str();
+                            global_config.profiles.remove(profile_name);
+                            if global_config.default_profile == profile_name {
+                                global_config.default_profile.clear();
+                            }
+                        }
+                        "list" => {
+                            if !args_count(3, arg_len, Ordering::Equal) { return; }
+
+                            for (profile_name, exec) in &config.profiles {
+                                let marker = if *profile_name == config.default_profile { " (default)" } else { "" };
+                                println!("{} = {}{}", profile_name.bold(), exec, marker);
+                            }
+                            return;
+                        }
+                        _ => {
+                            err_msg(&format!("invalid action {}", profile_action.bold()));
+                            return;
+                        }
                     }
                 }
                 _ => {
@@ -267,7 +663,7 @@ fn main() {
                 }
             }
 
-            confy::store(NAME, CONFIG, config).unwrap_or_else(|e| {
+            confy::store(NAME, CONFIG, global_config).unwrap_or_else(|e| {
                 err_msg(&format!("failed to save config: {e}"));
             });
         }
@@ -277,11 +673,346 @@ fn main() {
     }
 }
 
-fn open_godot(args: Vec<&str>) {
-    process::Command::new("godot")
-        .args(args)
-        .spawn()
-        .unwrap();
+fn open_godot(exec: &str, args: Vec<&str>) {
+    if let Err(e) = process::Command::new(exec).args(args).spawn() {
+        err_msg(&format!("failed to launch \"{exec}\": {e}"));
+    }
+}
+
+fn resolve_profile_exec<'a>(config: &'a Config, profile_flag: &Option<String>) -> Result<&'a str, String> {
+    let name = profile_flag.as_deref().unwrap_or(config.default_profile.as_str());
+    if name.is_empty() {
+        return Err(String::from("no profile selected; pass --profile <name> or set default_profile"));
+    }
+
+    config.profiles.get(name).map(String::as_str).ok_or_else(|| format!("unknown profile \"{name}\""))
+}
+
+fn resolve_targets(config: &Config, name: &str) -> Option<Vec<String>> {
+    match name.strip_prefix('@') {
+        Some(tag_name) => config.tags.get(tag_name).cloned(),
+        None => Some(vec!(name.to_string()))
+    }
+}
+
+fn capture_godot(exec: &str, args: &[&str]) -> io::Result<String> {
+    let output = process::Command::new(exec).args(args).output()?;
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    fs::metadata(path).map(|m| m.permissions().mode() & 0o111 != 0).unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+fn print_status_line(label: &str, ok: bool, detail: &str) {
+    if ok {
+        println!("{} {label}: {detail}", "ok".green().bold());
+    } else {
+        println!("{} {label}: {detail}", "missing".red().bold());
+    }
+}
+
+fn print_doctor_report(config: &Config, local_path: &Option<PathBuf>, profile_flag: &Option<String>, json: bool) {
+    let global_path = confy::get_configuration_file_path(NAME, CONFIG).ok();
+
+    let profile_name = profile_flag.as_deref().unwrap_or(config.default_profile.as_str());
+    let exec = if profile_name.is_empty() { None } else { config.profiles.get(profile_name) };
+
+    let exec_exists = exec.is_some_and(|e| Path::new(e).is_file());
+    let exec_executable = exec.is_some_and(|e| is_executable(Path::new(e)));
+    let godot_version = exec.filter(|_| exec_exists).and_then(|e| capture_godot(e, &["--version"]).ok());
+
+    let project_count = enumerate_projects(&config.project_dir).len();
+
+    let mut unset = Vec::new();
+    if config.project_dir.is_empty() { unset.push("project_dir"); }
+    if config.profiles.is_empty() { unset.push("profiles"); }
+    if config.default_profile.is_empty() { unset.push("default_profile"); }
+
+    if json {
+        let report = DoctorReport {
+            global_config: global_path.as_ref().map(|p| p.to_string_lossy().to_string()).unwrap_or_default(),
+            local_config: local_path.as_ref().map(|p| p.to_string_lossy().to_string()).unwrap_or_default(),
+            profile: profile_name.to_string(),
+            executable: exec.cloned().unwrap_or_default(),
+            executable_exists: exec_exists,
+            executable_executable: exec_executable,
+            godot_version: godot_version.clone().unwrap_or_default(),
+            os: env::consts::OS,
+            arch: env::consts::ARCH,
+            project_count,
+            unset_config_entries: unset.clone(),
+        };
+        match serde_json::to_string_pretty(&report) {
+            Ok(s) => println!("{s}"),
+            Err(e) => err_msg(&format!("failed to serialize doctor report: {e}"))
+        }
+        return;
+    }
+
+    println!("{}", "godot-cli doctor report".bold());
+    println!();
+    println!("{} {}", "global config:".green().bold(), global_path.map(|p| p.to_string_lossy().to_string()).unwrap_or_else(|| "unavailable".to_string()));
+    match local_path {
+        Some(p) => println!("{} {}", "local config:".green().bold(), p.to_string_lossy()),
+        None => println!("{} none found", "local config:".green().bold())
+    }
+    println!("{} {}/{}", "platform:".green().bold(), env::consts::OS, env::consts::ARCH);
+    println!();
+
+    if let Some(exec) = exec {
+        println!("{} {}", "profile:".green().bold(), profile_name);
+        print_status_line("executable exists", exec_exists, exec);
+        print_status_line("executable is runnable", exec_executable, exec);
+        match &godot_version {
+            Some(v) if !v.is_empty() => print_status_line("godot version", true, v),
+            _ => print_status_line("godot version", false, "could not be determined")
+        }
+    } else {
+        print_status_line("profile", false, "no active profile selected");
+    }
+    println!();
+
+    println!("{} {project_count}", "projects found:".green().bold());
+    println!();
+
+    if unset.is_empty() {
+        println!("{}", "all config entries are set".green().bold());
+    } else {
+        println!("{}", "unset config entries:".yellow().bold());
+        for entry in unset {
+            println!("  - {entry}");
+        }
+    }
+}
+
+fn find_local_config_path() -> Option<PathBuf> {
+    let mut dir = env::current_dir().ok()?;
+    loop {
+        let candidate = dir.join(LOCAL_CONFIG_FILE);
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+        if !dir.pop() {
+            return None;
+        }
+    }
+}
+
+fn load_config_file(path: &Path) -> Config {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| toml::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn merge_local_over_global(mut global: Config, local: &Config) -> Config {
+    if !local.project_dir.is_empty() { global.project_dir = local.project_dir.clone(); }
+    if !local.template_dir.is_empty() { global.template_dir = local.template_dir.clone(); }
+    if !local.default_profile.is_empty() { global.default_profile = local.default_profile.clone(); }
+    if !local.profiles.is_empty() { global.profiles = local.profiles.clone(); }
+    if !local.aliases.is_empty() { global.aliases = local.aliases.clone(); }
+    if !local.tags.is_empty() { global.tags = local.tags.clone(); }
+    global
+}
+
+fn write_local_config(path: &Path, config: &Config) -> io::Result<()> {
+    let serialized = toml::to_string_pretty(config)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+    fs::write(path, serialized)
+}
+
+const PROJECT_GODOT_TEMPLATE: &str = "[application]\n\nconfig/name=\"{name}\"\nrun/main_scene=\"res://main.tscn\"\n";
+const GODOT_GITIGNORE: &str = ".godot/\n*.import\nexport_presets.cfg\n";
+
+const EMPTY_MAIN_TSCN: &str = "[gd_scene load_steps=1 format=3]\n\n[node name=\"{name}\" type=\"Node\"]\n";
+const MAIN_2D_TSCN: &str = "[gd_scene load_steps=1 format=3]\n\n[node name=\"{name}\" type=\"Node2D\"]\n";
+const MAIN_3D_TSCN: &str = "[gd_scene load_steps=1 format=3]\n\n[node name=\"{name}\" type=\"Node3D\"]\n";
+
+fn scaffold_project(project_dir: &str, name: &str, template_id: &str, templates_dir: &str) -> io::Result<()> {
+    fs::create_dir_all(format!("{project_dir}/scenes"))?;
+    fs::create_dir_all(format!("{project_dir}/scripts"))?;
+    fs::create_dir_all(format!("{project_dir}/assets"))?;
+
+    for (rel_path, contents) in resolve_template_files(template_id, templates_dir)? {
+        let full_path = format!("{project_dir}/{rel_path}");
+        if let Some(parent) = Path::new(&full_path).parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(full_path, substitute_name(&contents, name))?;
+    }
+
+    fs::write(format!("{project_dir}/project.godot"), substitute_name(PROJECT_GODOT_TEMPLATE, name))?;
+    fs::write(format!("{project_dir}/.gitignore"), GODOT_GITIGNORE)?;
+
+    // best-effort: a missing git binary shouldn't fail project creation
+    process::Command::new("git").arg("init").arg(project_dir).output().ok();
+
+    Ok(())
+}
+
+fn resolve_templates_dir(config: &Config) -> String {
+    if !config.template_dir.is_empty() {
+        return config.template_dir.clone();
+    }
+
+    confy::get_configuration_file_path(NAME, CONFIG)
+        .ok()
+        .and_then(|path| path.parent().map(|p| p.join("templates").to_string_lossy().to_string()))
+        .unwrap_or_default()
+}
+
+fn resolve_template_files(template_id: &str, templates_dir: &str) -> io::Result<Vec<(String, String)>> {
+    if !templates_dir.is_empty() {
+        let custom_dir = format!("{templates_dir}/{template_id}");
+        if Path::new(&custom_dir).is_dir() {
+            return collect_template_files(Path::new(&custom_dir));
+        }
+    }
+
+    match builtin_template_files(template_id) {
+        Some(files) => Ok(files.into_iter().map(|(p, c)| (p.to_string(), c.to_string())).collect()),
+        None => Err(io::Error::new(io::ErrorKind::NotFound, format!("unknown template \"{template_id}\""))),
+    }
+}
+
+fn builtin_template_files(template_id: &str) -> Option<Vec<(&'static str, &'static str)>> {
+    match template_id {
+        "empty" => Some(vec![("main.tscn", EMPTY_MAIN_TSCN)]),
+        "2d" => Some(vec![("main.tscn", MAIN_2D_TSCN)]),
+        "3d" => Some(vec![("main.tscn", MAIN_3D_TSCN)]),
+        _ => None
+    }
+}
+
+fn collect_template_files(dir: &Path) -> io::Result<Vec<(String, String)>> {
+    let mut files = Vec::new();
+    collect_template_files_rec(dir, dir, &mut files)?;
+    Ok(files)
+}
+
+fn collect_template_files_rec(root: &Path, current: &Path, files: &mut Vec<(String, String)>) -> io::Result<()> {
+    for entry in fs::read_dir(current)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_template_files_rec(root, &path, files)?;
+        } else {
+            let rel = path.strip_prefix(root).unwrap().to_string_lossy().to_string();
+            files.push((rel, fs::read_to_string(&path).unwrap_or_default()));
+        }
+    }
+    Ok(())
+}
+
+fn substitute_name(text: &str, name: &str) -> String {
+    text.replace("{name}", name)
+}
+
+const ACTIONS: &[&str] = &["new", "open", "run", "list", "delete", "tag", "config", "doctor", "help", "completions"];
+const CONFIG_ENTRIES: &[&str] = &["project_dir", "template_dir", "default_profile"];
+
+fn enumerate_projects(dir: &str) -> Vec<String> {
+    let mut names = Vec::new();
+
+    let Ok(entries) = fs::read_dir(dir) else { return names; };
+    for entry in entries.flatten() {
+        let mut path = entry.path();
+        if path.is_file() { continue; }
+
+        path.push("project.godot");
+        if !path.is_file() { continue; }
+
+        names.push(entry.file_name().to_string_lossy().to_string());
+    }
+
+    names
+}
+
+fn completions_bash() -> String {
+    format!(
+        "_{NAME}_complete() {{\n\
+        \x20   local cur prev words cword\n\
+        \x20   _init_completion || return\n\n\
+        \x20   local actions=\"{actions}\"\n\
+        \x20   local entries=\"{entries}\"\n\n\
+        \x20   if [[ $cword -eq 1 ]]; then\n\
+        \x20       COMPREPLY=($(compgen -W \"$actions\" -- \"$cur\"))\n\
+        \x20       return\n\
+        \x20   fi\n\n\
+        \x20   case \"${{words[1]}}\" in\n\
+        \x20       open|run|delete)\n\
+        \x20           COMPREPLY=($(compgen -W \"$({NAME} list 2>/dev/null)\" -- \"$cur\"))\n\
+        \x20           ;;\n\
+        \x20       config)\n\
+        \x20           if [[ $cword -eq 2 ]]; then\n\
+        \x20               COMPREPLY=($(compgen -W \"get set delete clear where alias profile\" -- \"$cur\"))\n\
+        \x20           elif [[ $cword -eq 3 ]]; then\n\
+        \x20               COMPREPLY=($(compgen -W \"$entries\" -- \"$cur\"))\n\
+        \x20           fi\n\
+        \x20           ;;\n\
+        \x20   esac\n\
+        }}\n\
+        complete -F _{NAME}_complete {NAME}\n",
+        actions = ACTIONS.join(" "),
+        entries = CONFIG_ENTRIES.join(" ")
+    )
+}
+
+fn completions_zsh() -> String {
+    format!(
+        "#compdef {NAME}\n\n\
+        _{NAME}() {{\n\
+        \x20   local -a actions entries\n\
+        \x20   actions=({actions})\n\
+        \x20   entries=({entries})\n\n\
+        \x20   if (( CURRENT == 2 )); then\n\
+        \x20       _describe 'action' actions\n\
+        \x20       return\n\
+        \x20   fi\n\n\
+        \x20   case \"${{words[2]}}\" in\n\
+        \x20       open|run|delete)\n\
+        \x20           _describe 'project' \"($({NAME} list 2>/dev/null))\"\n\
+        \x20           ;;\n\
+        \x20       config)\n\
+        \x20           if (( CURRENT == 3 )); then\n\
+        \x20               _values 'action' get set delete clear where alias profile\n\
+        \x20           elif (( CURRENT == 4 )); then\n\
+        \x20               _describe 'entry' entries\n\
+        \x20           fi\n\
+        \x20           ;;\n\
+        \x20   esac\n\
+        }}\n\
+        compdef _{NAME} {NAME}\n",
+        actions = ACTIONS.join(" "),
+        entries = CONFIG_ENTRIES.join(" ")
+    )
+}
+
+fn completions_fish() -> String {
+    let mut script = String::new();
+
+    for action in ACTIONS {
+        script.push_str(&format!("complete -c {NAME} -n \"__fish_use_subcommand\" -a {action}\n"));
+    }
+
+    script.push_str(&format!(
+        "complete -c {NAME} -n \"__fish_seen_subcommand_from open run delete\" -a \"({NAME} list 2>/dev/null)\"\n"
+    ));
+    script.push_str(&format!(
+        "complete -c {NAME} -n \"__fish_seen_subcommand_from config\" -a \"get set delete clear where alias profile\"\n"
+    ));
+    for entry in CONFIG_ENTRIES {
+        script.push_str(&format!("complete -c {NAME} -n \"__fish_seen_subcommand_from config\" -a {entry}\n"));
+    }
+
+    script
 }
 
 fn prompt(msg: &str, cancel_msg: Option<&str>) -> bool {
@@ -339,26 +1070,34 @@ fn hint_msg(msg: &str) {
 
 fn print_action_help() {
     println!("{} - a convenience cli for godot", NAME.green().bold());
-    hint_msg(&format!("to force disable/enable the use of colors, use {} respectively\n", "--no-color/--force-color".bold()));
+    hint_msg(&format!("to force disable/enable the use of colors, use {} respectively", "--no-color/--force-color".bold()));
+    hint_msg(&format!("to pick an executable profile for this invocation, use {}\n", "--profile <name>".bold()));
 
     println!("{} get/set entry [value] | configure the cli", "config".bold());
-    println!("{}/{} name | create a project", "new".bold(), "create".bold());
-    println!("{} name | open a project", "open".bold());
-    println!("{} name [n] | run a project [n times]", "run".bold());
+    println!("{}/{} name [--template id] | create a project", "new".bold(), "create".bold());
+    println!("{} name/@tag | open a project, or every project in a tag", "open".bold());
+    println!("{} name/@tag [n] | run a project [n times], or every project in a tag", "run".bold());
     println!("{} | list all projects", "list".bold());
-    println!("{}/{} name | delete a project\n", "delete".bold(), "remove".bold());
+    println!("{}/{} name | delete a project", "delete".bold(), "remove".bold());
+    println!("{} add/remove/list tag [project] | manage project tags", "tag".bold());
+    println!("{} bash/zsh/fish | print a shell completion script", "completions".bold());
+    println!("{}/{} [--json] | print an environment diagnostics report\n", "doctor".bold(), "diagnose".bold());
 }
 
 fn print_config_help() {
     println!("  {}", "actions:".cyan().bold());
     println!("{}: get a config entry", "get".bold());
-    println!("{}: set a config entry", "set".bold());
+    println!("{}: set a config entry, pass {} to write to the nearest {}", "set".bold(), "--local".bold(), LOCAL_CONFIG_FILE.bold());
     println!("{}: clear a config entry", "delete/remove".bold());
-    println!("{}: clear the entire config\n", "clear".bold());
+    println!("{}: clear the entire config", "clear".bold());
+    println!("{}: show which file (global/local) each entry is effectively read from", "where".bold());
+    println!("{}: get/set/delete/list user-defined command aliases", "alias".bold());
+    println!("{}: add/remove/list named executable profiles\n", "profile".bold());
 
     println!("  {}", "entries:".cyan().bold());
-    println!("{}: path to the executable", "godot_exec".bold());
-    println!("{}: directory containing projects\n", "project_dir".bold());
+    println!("{}: directory containing projects", "project_dir".bold());
+    println!("{}: directory to resolve \"new --template\" ids from", "template_dir".bold());
+    println!("{}: the profile used when {} is not passed\n", "default_profile".bold(), "--profile".bold());
 }
 
 fn print_missing_config_notice(settings: Vec<&str>) {
@@ -369,6 +1108,12 @@ fn print_missing_config_notice(settings: Vec<&str>) {
     ));
 }
 
+fn print_missing_profile_notice() {
+    warn_msg(&format!("please add an executable profile first, by using {}",
+            "godot-cli config profile add <name> <path>".bold()
+    ));
+}
+
 fn print_config_error(e: confy::ConfyError) {
     err_msg(&format!("failed to load config: {e}"));
 }